@@ -0,0 +1,140 @@
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use napi::bindgen_prelude::*;
+
+use crate::crypto::CryptoError;
+
+const BEGIN_LABEL: &str = "-----BEGIN DEVSHARE MESSAGE-----";
+const END_LABEL: &str = "-----END-----";
+const LINE_WIDTH: usize = 64;
+
+/// ASCII-armors `data` as a self-describing, CRC-checked text block so it
+/// can move through channels that only carry text (chat, email, paste).
+pub fn armor(data: &[u8]) -> String {
+  let payload = STANDARD.encode(data);
+  let checksum = STANDARD.encode(crc24(data));
+
+  let mut armored = String::new();
+  armored.push_str(BEGIN_LABEL);
+  armored.push_str("\n\n");
+
+  for line in payload.as_bytes().chunks(LINE_WIDTH) {
+    armored.push_str(std::str::from_utf8(line).expect("base64 output is ASCII"));
+    armored.push('\n');
+  }
+
+  armored.push('=');
+  armored.push_str(&checksum);
+  armored.push('\n');
+  armored.push_str(END_LABEL);
+  armored.push('\n');
+
+  armored
+}
+
+/// Reverses [`armor`], verifying the CRC checksum before returning the
+/// decoded payload.
+pub fn dearmor(armored: &str) -> Result<Vec<u8>> {
+  let mut lines = armored.lines();
+
+  let begin = lines
+    .next()
+    .ok_or_else(|| CryptoError::InvalidKey("Empty armored message".to_string()))?;
+  if begin.trim() != BEGIN_LABEL {
+    return Err(CryptoError::InvalidKey("Missing DEVSHARE MESSAGE header".to_string()).into());
+  }
+
+  let mut payload = String::new();
+  let mut checksum_line = None;
+
+  for line in lines {
+    let trimmed = line.trim();
+
+    if trimmed == END_LABEL {
+      break;
+    }
+    if trimmed.is_empty() {
+      continue;
+    }
+    if let Some(stripped) = trimmed.strip_prefix('=') {
+      checksum_line = Some(stripped.to_string());
+    } else {
+      payload.push_str(trimmed);
+    }
+  }
+
+  let checksum_b64 =
+    checksum_line.ok_or_else(|| CryptoError::InvalidKey("Missing armor checksum".to_string()))?;
+
+  let data = STANDARD
+    .decode(payload)
+    .map_err(|e| CryptoError::InvalidKey(format!("Invalid base64 payload: {}", e)))?;
+
+  let expected_checksum = STANDARD.encode(crc24(&data));
+  if expected_checksum != checksum_b64 {
+    return Err(CryptoError::InvalidKey("Armor checksum mismatch".to_string()).into());
+  }
+
+  Ok(data)
+}
+
+/// The CRC-24 variant specified by RFC 4880 (OpenPGP), used here purely as a
+/// transport integrity check rather than anything cryptographic.
+fn crc24(data: &[u8]) -> [u8; 3] {
+  const CRC24_INIT: u32 = 0x00B7_04CE;
+  const CRC24_POLY: u32 = 0x0186_4CFB;
+
+  let mut crc = CRC24_INIT;
+  for &byte in data {
+    crc ^= (byte as u32) << 16;
+    for _ in 0..8 {
+      crc <<= 1;
+      if crc & 0x0100_0000 != 0 {
+        crc ^= CRC24_POLY;
+      }
+    }
+  }
+
+  let crc = crc & 0x00FF_FFFF;
+  [(crc >> 16) as u8, (crc >> 8) as u8, crc as u8]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_armor_dearmor_round_trip() {
+    let data = b"devshare sealed message payload";
+
+    let armored = armor(data);
+    assert!(armored.starts_with(BEGIN_LABEL));
+    assert!(armored.trim_end().ends_with(END_LABEL));
+
+    let recovered = dearmor(&armored).unwrap();
+    assert_eq!(recovered, data);
+  }
+
+  #[test]
+  fn test_dearmor_rejects_tampered_payload() {
+    let armored = armor(b"original payload data for crc check");
+    let mut chars: Vec<char> = armored.chars().collect();
+
+    // Flip one base64 character in the payload (just past the blank line
+    // that follows the header) so the CRC no longer matches.
+    let payload_start = armored.find("\n\n").unwrap() + 2;
+    let flip_index = chars[payload_start..]
+      .iter()
+      .position(|c| c.is_ascii_alphanumeric())
+      .map(|i| payload_start + i)
+      .unwrap();
+    chars[flip_index] = if chars[flip_index] == 'A' { 'B' } else { 'A' };
+
+    let tampered: String = chars.into_iter().collect();
+    assert!(dearmor(&tampered).is_err());
+  }
+
+  #[test]
+  fn test_dearmor_rejects_missing_header() {
+    assert!(dearmor("not an armored message").is_err());
+  }
+}
@@ -0,0 +1,506 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use bls12_381::hash_to_curve::{ExpandMsgXmd, HashToCurve};
+use bls12_381::{pairing, G1Affine, G1Projective, G2Affine, G2Projective, Scalar};
+use ff::Field;
+use group::Group;
+use napi::bindgen_prelude::*;
+use rand::rngs::OsRng;
+use sha2::{Digest as Sha2Digest, Sha256};
+
+use super::CryptoError;
+use crate::{BbsHiddenResponse, BbsKeyPair, BbsProof, BbsSignature};
+
+/// Domain separation tag for deriving the message generators via hash-to-curve
+/// (RFC 9380). The generators must have an *unknown* discrete log relative to
+/// one another — a scalar multiple of a known generator would let anyone who
+/// can compute that scalar forge signatures on arbitrary messages.
+const GENERATOR_DST: &[u8] = b"DEVSHARE_BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+/// Fixed public generators for a given message count, derived via
+/// hash-to-curve from labels so every signer/verifier agrees on them without
+/// a trusted setup, and without anyone knowing the discrete log between them.
+struct Generators {
+  g1: G1Projective,
+  h0: G1Projective,
+  h: Vec<G1Projective>,
+}
+
+fn generators(message_count: usize) -> Generators {
+  let g1 = generator_point(b"g1");
+  let h0 = generator_point(b"h0");
+  let h = (0..message_count)
+    .map(|i| generator_point(format!("h{}", i).as_bytes()))
+    .collect();
+
+  Generators { g1, h0, h }
+}
+
+fn generator_point(label: &[u8]) -> G1Projective {
+  <G1Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(label, GENERATOR_DST)
+}
+
+/// Maps an arbitrary byte attribute to a scalar by hashing it with SHA-256
+/// under two domain-separated labels and reducing the 512-bit result mod
+/// the curve order.
+fn hash_to_scalar(message: &[u8]) -> Scalar {
+  let mut hasher = Sha256::new();
+  hasher.update(b"BBS_MESSAGE_1");
+  hasher.update(message);
+  let h1 = hasher.finalize();
+
+  let mut hasher = Sha256::new();
+  hasher.update(b"BBS_MESSAGE_2");
+  hasher.update(message);
+  let h2 = hasher.finalize();
+
+  wide_scalar(&h1, &h2)
+}
+
+fn wide_scalar(h1: &[u8], h2: &[u8]) -> Scalar {
+  let mut wide = [0u8; 64];
+  wide[..32].copy_from_slice(h1);
+  wide[32..].copy_from_slice(h2);
+  Scalar::from_bytes_wide(&wide)
+}
+
+fn compute_b(gens: &Generators, s: Scalar, message_scalars: &[Scalar]) -> G1Projective {
+  let mut b = gens.g1 + gens.h0 * s;
+  for (hi, mi) in gens.h.iter().zip(message_scalars.iter()) {
+    b += hi * mi;
+  }
+  b
+}
+
+fn compute_challenge(
+  a_prime: &G1Projective,
+  a_bar: &G1Projective,
+  t_commit: &G1Projective,
+  revealed: &[(u32, &[u8])],
+  nonce: &[u8],
+) -> Scalar {
+  let mut hasher = Sha256::new();
+  hasher.update(G1Affine::from(a_prime).to_compressed());
+  hasher.update(G1Affine::from(a_bar).to_compressed());
+  hasher.update(G1Affine::from(t_commit).to_compressed());
+  for (index, message) in revealed {
+    hasher.update(index.to_be_bytes());
+    hasher.update((message.len() as u32).to_be_bytes());
+    hasher.update(message);
+  }
+  hasher.update(nonce);
+  let h1 = hasher.finalize();
+
+  let mut hasher = Sha256::new();
+  hasher.update(b"BBS_CHALLENGE_2");
+  hasher.update(h1);
+  let h2 = hasher.finalize();
+
+  wide_scalar(&h1, &h2)
+}
+
+fn parse_scalar(hex_str: &str) -> Result<Scalar> {
+  let bytes =
+    hex::decode(hex_str).map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+  if bytes.len() != 32 {
+    return Err(CryptoError::InvalidKey("Scalar must be 32 bytes".to_string()).into());
+  }
+
+  let mut array = [0u8; 32];
+  array.copy_from_slice(&bytes);
+
+  Option::<Scalar>::from(Scalar::from_bytes(&array))
+    .ok_or_else(|| CryptoError::InvalidKey("Invalid scalar encoding".to_string()).into())
+}
+
+fn parse_g1(hex_str: &str) -> Result<G1Projective> {
+  let bytes =
+    hex::decode(hex_str).map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+  if bytes.len() != 48 {
+    return Err(CryptoError::InvalidKey("G1 element must be 48 bytes".to_string()).into());
+  }
+
+  let mut array = [0u8; 48];
+  array.copy_from_slice(&bytes);
+
+  Option::<G1Affine>::from(G1Affine::from_compressed(&array))
+    .map(G1Projective::from)
+    .ok_or_else(|| CryptoError::InvalidKey("Invalid G1 point encoding".to_string()).into())
+}
+
+fn parse_g2(hex_str: &str) -> Result<G2Projective> {
+  let bytes =
+    hex::decode(hex_str).map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+  if bytes.len() != 96 {
+    return Err(CryptoError::InvalidKey("G2 element must be 96 bytes".to_string()).into());
+  }
+
+  let mut array = [0u8; 96];
+  array.copy_from_slice(&bytes);
+
+  Option::<G2Affine>::from(G2Affine::from_compressed(&array))
+    .map(G2Projective::from)
+    .ok_or_else(|| CryptoError::InvalidKey("Invalid G2 point encoding".to_string()).into())
+}
+
+pub fn bbs_keygen() -> Result<BbsKeyPair> {
+  let mut rng = OsRng;
+  let x = Scalar::random(&mut rng);
+  let w = G2Projective::generator() * x;
+
+  Ok(BbsKeyPair {
+    secret_key: hex::encode(x.to_bytes()),
+    public_key: hex::encode(G2Affine::from(w).to_compressed()),
+  })
+}
+
+pub fn bbs_sign(secret_key_hex: &str, messages: &[Vec<u8>]) -> Result<BbsSignature> {
+  let x = parse_scalar(secret_key_hex)?;
+  let mut rng = OsRng;
+
+  let message_scalars: Vec<Scalar> = messages.iter().map(|m| hash_to_scalar(m)).collect();
+  let gens = generators(messages.len());
+
+  let e = Scalar::random(&mut rng);
+  let inverse = Option::<Scalar>::from((x + e).invert()).ok_or_else(|| {
+    CryptoError::EncryptionFailed("signing exponent collided with zero, retry signing".to_string())
+  })?;
+
+  let s = Scalar::random(&mut rng);
+  let b = compute_b(&gens, s, &message_scalars);
+  let a = b * inverse;
+
+  Ok(BbsSignature {
+    a: hex::encode(G1Affine::from(a).to_compressed()),
+    e: hex::encode(e.to_bytes()),
+    s: hex::encode(s.to_bytes()),
+  })
+}
+
+pub fn bbs_verify(
+  public_key_hex: &str,
+  signature: &BbsSignature,
+  messages: &[Vec<u8>],
+) -> Result<bool> {
+  let w = parse_g2(public_key_hex)?;
+  let a = parse_g1(&signature.a)?;
+  let e = parse_scalar(&signature.e)?;
+  let s = parse_scalar(&signature.s)?;
+
+  let message_scalars: Vec<Scalar> = messages.iter().map(|m| hash_to_scalar(m)).collect();
+  let gens = generators(messages.len());
+  let b = compute_b(&gens, s, &message_scalars);
+
+  let lhs = pairing(
+    &G1Affine::from(a),
+    &G2Affine::from(w + G2Projective::generator() * e),
+  );
+  let rhs = pairing(&G1Affine::from(b), &G2Affine::generator());
+
+  Ok(lhs == rhs)
+}
+
+pub fn bbs_create_proof(
+  public_key_hex: &str,
+  signature: &BbsSignature,
+  messages: &[Vec<u8>],
+  revealed_indices: &[u32],
+  nonce: &[u8],
+) -> Result<BbsProof> {
+  // Validates the public key shape; the proof itself never uses W directly,
+  // it just has to be the right kind of point for later verification.
+  parse_g2(public_key_hex)?;
+
+  let a = parse_g1(&signature.a)?;
+  let e = parse_scalar(&signature.e)?;
+  let s = parse_scalar(&signature.s)?;
+
+  let message_scalars: Vec<Scalar> = messages.iter().map(|m| hash_to_scalar(m)).collect();
+  let gens = generators(messages.len());
+
+  let revealed_set: BTreeSet<usize> = revealed_indices.iter().map(|&i| i as usize).collect();
+  for &i in &revealed_set {
+    if i >= messages.len() {
+      return Err(CryptoError::InvalidKey("revealed index out of range".to_string()).into());
+    }
+  }
+  let hidden_indices: Vec<usize> = (0..messages.len())
+    .filter(|i| !revealed_set.contains(i))
+    .collect();
+
+  let mut c_r = gens.g1;
+  for &i in &revealed_set {
+    c_r += gens.h[i] * message_scalars[i];
+  }
+
+  let mut rng = OsRng;
+  let r = Scalar::random(&mut rng);
+
+  let b = compute_b(&gens, s, &message_scalars);
+  let a_prime = a * r;
+  let a_bar = b * r - a_prime * e;
+
+  let s_tilde = s * r;
+  let hidden_tildes: Vec<(usize, Scalar)> = hidden_indices
+    .iter()
+    .map(|&i| (i, message_scalars[i] * r))
+    .collect();
+
+  // Abar = r·C_R + s~·h0 - e·A' + Σ_{hidden} m~_j·h_j is a linear relation in
+  // the witnesses (r, e, s~, {m~_j}); proving it in zero knowledge keeps `e`
+  // (a per-credential constant) hidden so presentations of the same
+  // credential aren't linkable by it.
+  let rho_r = Scalar::random(&mut rng);
+  let rho_e = Scalar::random(&mut rng);
+  let rho_s = Scalar::random(&mut rng);
+  let rho_hidden: Vec<Scalar> = hidden_tildes.iter().map(|_| Scalar::random(&mut rng)).collect();
+
+  let mut t_commit = c_r * rho_r + gens.h0 * rho_s - a_prime * rho_e;
+  for ((i, _), rho) in hidden_tildes.iter().zip(rho_hidden.iter()) {
+    t_commit += gens.h[*i] * rho;
+  }
+
+  let revealed_pairs: Vec<(u32, &[u8])> = revealed_set
+    .iter()
+    .map(|&i| (i as u32, messages[i].as_slice()))
+    .collect();
+  let challenge = compute_challenge(&a_prime, &a_bar, &t_commit, &revealed_pairs, nonce);
+
+  let z_r = rho_r + challenge * r;
+  let z_e = rho_e + challenge * e;
+  let z_s = rho_s + challenge * s_tilde;
+  let hidden_responses: Vec<BbsHiddenResponse> = hidden_tildes
+    .iter()
+    .zip(rho_hidden.iter())
+    .map(|((i, m_tilde), rho)| BbsHiddenResponse {
+      index: *i as u32,
+      response: hex::encode((rho + challenge * m_tilde).to_bytes()),
+    })
+    .collect();
+
+  Ok(BbsProof {
+    a_prime: hex::encode(G1Affine::from(a_prime).to_compressed()),
+    a_bar: hex::encode(G1Affine::from(a_bar).to_compressed()),
+    challenge: hex::encode(challenge.to_bytes()),
+    z_r: hex::encode(z_r.to_bytes()),
+    z_e: hex::encode(z_e.to_bytes()),
+    z_s: hex::encode(z_s.to_bytes()),
+    hidden_responses,
+  })
+}
+
+pub fn bbs_verify_proof(
+  public_key_hex: &str,
+  proof: &BbsProof,
+  message_count: u32,
+  revealed_indices: &[u32],
+  revealed_messages: &[Vec<u8>],
+  nonce: &[u8],
+) -> Result<bool> {
+  if revealed_indices.len() != revealed_messages.len() {
+    return Err(
+      CryptoError::InvalidSignature(
+        "revealed_indices and revealed_messages must have the same length".to_string(),
+      )
+      .into(),
+    );
+  }
+
+  let w = parse_g2(public_key_hex)?;
+  let a_prime = parse_g1(&proof.a_prime)?;
+  let a_bar = parse_g1(&proof.a_bar)?;
+  let challenge = parse_scalar(&proof.challenge)?;
+  let z_r = parse_scalar(&proof.z_r)?;
+  let z_e = parse_scalar(&proof.z_e)?;
+  let z_s = parse_scalar(&proof.z_s)?;
+
+  if bool::from(G1Affine::from(a_prime).is_identity()) {
+    return Ok(false);
+  }
+
+  // Core signature validity: e(Abar, G2) == e(A', W), independent of the
+  // blinding factor the holder used.
+  let lhs = pairing(&G1Affine::from(a_bar), &G2Affine::generator());
+  let rhs = pairing(&G1Affine::from(a_prime), &G2Affine::from(w));
+  if lhs != rhs {
+    return Ok(false);
+  }
+
+  let message_count = message_count as usize;
+  let gens = generators(message_count);
+
+  let mut revealed_pairs: Vec<(usize, Vec<u8>)> = revealed_indices
+    .iter()
+    .zip(revealed_messages.iter())
+    .map(|(&i, m)| (i as usize, m.clone()))
+    .collect();
+  revealed_pairs.sort_by_key(|(i, _)| *i);
+
+  for (i, _) in &revealed_pairs {
+    if *i >= message_count {
+      return Err(CryptoError::InvalidSignature("revealed index out of range".to_string()).into());
+    }
+  }
+
+  let mut c_r = gens.g1;
+  for (i, m) in &revealed_pairs {
+    c_r += gens.h[*i] * hash_to_scalar(m);
+  }
+
+  let revealed_set: BTreeSet<usize> = revealed_pairs.iter().map(|(i, _)| *i).collect();
+  let hidden_indices: Vec<usize> = (0..message_count)
+    .filter(|i| !revealed_set.contains(i))
+    .collect();
+
+  if hidden_indices.len() != proof.hidden_responses.len() {
+    return Ok(false);
+  }
+
+  let mut hidden_by_index: BTreeMap<usize, Scalar> = BTreeMap::new();
+  for resp in &proof.hidden_responses {
+    hidden_by_index.insert(resp.index as usize, parse_scalar(&resp.response)?);
+  }
+
+  if hidden_indices.iter().any(|i| !hidden_by_index.contains_key(i)) {
+    return Ok(false);
+  }
+
+  // Recompute the Schnorr commitment for the relation:
+  //   Abar = r·C_R + s~·h0 - e·A' + Σ_{hidden} m~_j·h_j
+  let mut t_commit_prime = c_r * z_r + gens.h0 * z_s - a_prime * z_e - a_bar * challenge;
+  for &i in &hidden_indices {
+    t_commit_prime += gens.h[i] * hidden_by_index[&i];
+  }
+
+  let revealed_pairs_refs: Vec<(u32, &[u8])> = revealed_pairs
+    .iter()
+    .map(|(i, m)| (*i as u32, m.as_slice()))
+    .collect();
+  let expected_challenge =
+    compute_challenge(&a_prime, &a_bar, &t_commit_prime, &revealed_pairs_refs, nonce);
+
+  Ok(expected_challenge == challenge)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn messages() -> Vec<Vec<u8>> {
+    vec![
+      b"name:alice".to_vec(),
+      b"dob:1990-01-01".to_vec(),
+      b"role:admin".to_vec(),
+    ]
+  }
+
+  #[test]
+  fn test_bbs_sign_and_verify() {
+    let keypair = bbs_keygen().unwrap();
+    let msgs = messages();
+
+    let signature = bbs_sign(&keypair.secret_key, &msgs).unwrap();
+    let is_valid = bbs_verify(&keypair.public_key, &signature, &msgs).unwrap();
+
+    assert!(is_valid);
+  }
+
+  #[test]
+  fn test_bbs_verify_rejects_tampered_message() {
+    let keypair = bbs_keygen().unwrap();
+    let msgs = messages();
+
+    let signature = bbs_sign(&keypair.secret_key, &msgs).unwrap();
+
+    let mut tampered = msgs.clone();
+    tampered[1] = b"dob:1999-01-01".to_vec();
+
+    assert!(!bbs_verify(&keypair.public_key, &signature, &tampered).unwrap());
+  }
+
+  #[test]
+  fn test_bbs_selective_disclosure_round_trip() {
+    let keypair = bbs_keygen().unwrap();
+    let msgs = messages();
+    let nonce = b"session-nonce";
+
+    let signature = bbs_sign(&keypair.secret_key, &msgs).unwrap();
+    let revealed_indices = [0u32, 2];
+
+    let proof =
+      bbs_create_proof(&keypair.public_key, &signature, &msgs, &revealed_indices, nonce).unwrap();
+
+    let revealed_messages = vec![msgs[0].clone(), msgs[2].clone()];
+    let is_valid = bbs_verify_proof(
+      &keypair.public_key,
+      &proof,
+      msgs.len() as u32,
+      &revealed_indices,
+      &revealed_messages,
+      nonce,
+    )
+    .unwrap();
+
+    assert!(is_valid);
+  }
+
+  #[test]
+  fn test_bbs_proof_rejects_wrong_revealed_message() {
+    let keypair = bbs_keygen().unwrap();
+    let msgs = messages();
+    let nonce = b"session-nonce";
+
+    let signature = bbs_sign(&keypair.secret_key, &msgs).unwrap();
+    let revealed_indices = [0u32];
+
+    let proof =
+      bbs_create_proof(&keypair.public_key, &signature, &msgs, &revealed_indices, nonce).unwrap();
+
+    let wrong_revealed = vec![b"name:bob".to_vec()];
+    let is_valid = bbs_verify_proof(
+      &keypair.public_key,
+      &proof,
+      msgs.len() as u32,
+      &revealed_indices,
+      &wrong_revealed,
+      nonce,
+    )
+    .unwrap();
+
+    assert!(!is_valid);
+  }
+
+  #[test]
+  fn test_bbs_proof_rejects_wrong_nonce() {
+    let keypair = bbs_keygen().unwrap();
+    let msgs = messages();
+
+    let signature = bbs_sign(&keypair.secret_key, &msgs).unwrap();
+    let revealed_indices = [1u32];
+
+    let proof = bbs_create_proof(
+      &keypair.public_key,
+      &signature,
+      &msgs,
+      &revealed_indices,
+      b"correct-nonce",
+    )
+    .unwrap();
+
+    let revealed_messages = vec![msgs[1].clone()];
+    let is_valid = bbs_verify_proof(
+      &keypair.public_key,
+      &proof,
+      msgs.len() as u32,
+      &revealed_indices,
+      &revealed_messages,
+      b"wrong-nonce",
+    )
+    .unwrap();
+
+    assert!(!is_valid);
+  }
+}
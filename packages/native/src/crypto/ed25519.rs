@@ -0,0 +1,203 @@
+use std::str::FromStr;
+
+use ed25519_dalek::{Signature as DalekSignature, Signer, SigningKey, Verifier, VerifyingKey};
+use napi::bindgen_prelude::*;
+use rand::rngs::OsRng;
+use zeroize::Zeroize;
+
+use super::secret_bytes::SecretBytes;
+use super::CryptoError;
+use crate::KeyPair;
+
+/// A validated ed25519 private key. Once constructed, signing with it cannot
+/// fail. The underlying bytes are wiped when this value is dropped.
+pub struct Ed25519SecretKey(SecretBytes);
+
+impl Ed25519SecretKey {
+  pub fn from_bytes(bytes: [u8; 32]) -> Self {
+    Ed25519SecretKey(SecretBytes::new(bytes))
+  }
+
+  pub fn from_hex(hex_str: &str) -> Result<Self, CryptoError> {
+    let mut bytes =
+      hex::decode(hex_str).map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+    if bytes.len() != 32 {
+      bytes.zeroize();
+      return Err(CryptoError::InvalidKey("Private key must be 32 bytes".to_string()));
+    }
+
+    let mut array = [0u8; 32];
+    array.copy_from_slice(&bytes);
+    bytes.zeroize();
+
+    let secret_key = Self::from_bytes(array);
+    array.zeroize();
+
+    Ok(secret_key)
+  }
+
+  fn signing_key(&self) -> SigningKey {
+    SigningKey::from_bytes(self.0.as_bytes())
+  }
+
+  pub fn public_key(&self) -> Ed25519PublicKey {
+    Ed25519PublicKey(self.signing_key().verifying_key())
+  }
+
+  pub fn sign(&self, message: &[u8]) -> Ed25519Signature {
+    Ed25519Signature(self.signing_key().sign(message))
+  }
+
+  pub fn to_hex(&self) -> String {
+    hex::encode(self.0.as_bytes())
+  }
+
+  pub fn zeroize(&mut self) {
+    self.0.zeroize();
+  }
+}
+
+impl FromStr for Ed25519SecretKey {
+  type Err = CryptoError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_hex(s)
+  }
+}
+
+/// A validated ed25519 public key.
+pub struct Ed25519PublicKey(VerifyingKey);
+
+impl Ed25519PublicKey {
+  pub fn from_bytes(bytes: [u8; 32]) -> Result<Self, CryptoError> {
+    let key = VerifyingKey::from_bytes(&bytes)
+      .map_err(|e| CryptoError::InvalidKey(format!("Invalid public key: {}", e)))?;
+    Ok(Ed25519PublicKey(key))
+  }
+
+  pub fn from_hex(hex_str: &str) -> Result<Self, CryptoError> {
+    let bytes =
+      hex::decode(hex_str).map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+    if bytes.len() != 32 {
+      return Err(CryptoError::InvalidKey("Public key must be 32 bytes".to_string()));
+    }
+
+    let mut array = [0u8; 32];
+    array.copy_from_slice(&bytes);
+    Self::from_bytes(array)
+  }
+
+  pub fn verify(&self, message: &[u8], signature: &Ed25519Signature) -> bool {
+    self.0.verify(message, &signature.0).is_ok()
+  }
+
+  pub fn to_hex(&self) -> String {
+    hex::encode(self.0.to_bytes())
+  }
+}
+
+impl FromStr for Ed25519PublicKey {
+  type Err = CryptoError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_hex(s)
+  }
+}
+
+/// A validated ed25519 signature.
+pub struct Ed25519Signature(DalekSignature);
+
+impl Ed25519Signature {
+  pub fn from_bytes(bytes: [u8; 64]) -> Self {
+    Ed25519Signature(DalekSignature::from_bytes(&bytes))
+  }
+
+  pub fn from_hex(hex_str: &str) -> Result<Self, CryptoError> {
+    let bytes = hex::decode(hex_str)
+      .map_err(|e| CryptoError::InvalidSignature(format!("Invalid hex: {}", e)))?;
+
+    if bytes.len() != 64 {
+      return Err(CryptoError::InvalidSignature(
+        "Signature must be 64 bytes".to_string(),
+      ));
+    }
+
+    let mut array = [0u8; 64];
+    array.copy_from_slice(&bytes);
+    Ok(Self::from_bytes(array))
+  }
+
+  pub fn to_hex(&self) -> String {
+    hex::encode(self.0.to_bytes())
+  }
+}
+
+impl FromStr for Ed25519Signature {
+  type Err = CryptoError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_hex(s)
+  }
+}
+
+pub fn generate_ed25519_keypair() -> Result<KeyPair> {
+  let mut csprng = OsRng;
+  let signing_key = SigningKey::generate(&mut csprng);
+  let secret_key = Ed25519SecretKey::from_bytes(signing_key.to_bytes());
+
+  Ok(KeyPair {
+    public_key: secret_key.public_key().to_hex(),
+    private_key: secret_key.to_hex(),
+  })
+}
+
+pub fn sign_with_ed25519(private_key_hex: &str, data: &[u8]) -> Result<String> {
+  let secret_key = Ed25519SecretKey::from_hex(private_key_hex)?;
+  Ok(secret_key.sign(data).to_hex())
+}
+
+pub fn verify_ed25519_signature(
+  public_key_hex: &str,
+  signature_hex: &str,
+  data: &[u8],
+) -> Result<bool> {
+  let public_key = Ed25519PublicKey::from_hex(public_key_hex)?;
+  let signature = Ed25519Signature::from_hex(signature_hex)?;
+
+  Ok(public_key.verify(data, &signature))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_keypair_generation() {
+    let keypair = generate_ed25519_keypair().unwrap();
+    assert_eq!(keypair.public_key.len(), 64); // 32 bytes as hex
+    assert_eq!(keypair.private_key.len(), 64); // 32 bytes as hex
+  }
+
+  #[test]
+  fn test_sign_and_verify() {
+    let keypair = generate_ed25519_keypair().unwrap();
+    let data = b"a message of arbitrary length, not a fixed-size digest";
+
+    let signature = sign_with_ed25519(&keypair.private_key, data).unwrap();
+    let is_valid = verify_ed25519_signature(&keypair.public_key, &signature, data).unwrap();
+
+    assert!(is_valid);
+  }
+
+  #[test]
+  fn test_typed_core_is_infallible_once_constructed() {
+    let secret_key = Ed25519SecretKey::from_bytes([1u8; 32]);
+    let public_key = secret_key.public_key();
+    let message = b"hello";
+
+    let signature = secret_key.sign(message);
+    assert!(public_key.verify(message, &signature));
+  }
+}
@@ -2,12 +2,27 @@ use aes_gcm::{
   aead::{Aead, KeyInit},
   Aes256Gcm, Nonce,
 };
-use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
 use napi::bindgen_prelude::*;
 use rand::rngs::OsRng;
-use sha2::{Digest, Sha256};
-
-use crate::KeyPair;
+use sha2::{Digest as Sha2Digest, Sha256};
+use zeroize::Zeroize;
+
+mod bbs;
+mod ed25519;
+mod sealed;
+mod secp256k1;
+mod secret_bytes;
+mod types;
+mod x25519;
+
+use secret_bytes::SecretBytes;
+
+pub use bbs::*;
+pub use ed25519::*;
+pub use sealed::*;
+pub use secp256k1::*;
+pub use types::*;
+pub use x25519::*;
 
 #[derive(thiserror::Error, Debug)]
 pub enum CryptoError {
@@ -36,73 +51,6 @@ pub fn sha256_hash(data: &[u8]) -> Result<Buffer> {
   Ok(Buffer::from(result.as_slice()))
 }
 
-pub fn generate_ed25519_keypair() -> Result<KeyPair> {
-  let mut csprng = OsRng;
-  let signing_key = SigningKey::generate(&mut csprng);
-  let verifying_key = signing_key.verifying_key();
-
-  let private_key = hex::encode(signing_key.to_bytes());
-  let public_key = hex::encode(verifying_key.to_bytes());
-
-  Ok(KeyPair {
-    public_key,
-    private_key,
-  })
-}
-
-pub fn sign_with_ed25519(private_key_hex: &str, data: &[u8]) -> Result<String> {
-  let private_key_bytes = hex::decode(private_key_hex)
-    .map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
-
-  if private_key_bytes.len() != 32 {
-    return Err(CryptoError::InvalidKey("Private key must be 32 bytes".to_string()).into());
-  }
-
-  let mut key_array = [0u8; 32];
-  key_array.copy_from_slice(&private_key_bytes);
-
-  let signing_key = SigningKey::from_bytes(&key_array);
-  let signature = signing_key.sign(data);
-
-  Ok(hex::encode(signature.to_bytes()))
-}
-
-pub fn verify_ed25519_signature(
-  public_key_hex: &str,
-  signature_hex: &str,
-  data: &[u8],
-) -> Result<bool> {
-  let public_key_bytes = hex::decode(public_key_hex)
-    .map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
-
-  let signature_bytes = hex::decode(signature_hex)
-    .map_err(|e| CryptoError::InvalidSignature(format!("Invalid hex: {}", e)))?;
-
-  if public_key_bytes.len() != 32 {
-    return Err(CryptoError::InvalidKey("Public key must be 32 bytes".to_string()).into());
-  }
-
-  if signature_bytes.len() != 64 {
-    return Err(CryptoError::InvalidSignature("Signature must be 64 bytes".to_string()).into());
-  }
-
-  let mut key_array = [0u8; 32];
-  key_array.copy_from_slice(&public_key_bytes);
-
-  let mut sig_array = [0u8; 64];
-  sig_array.copy_from_slice(&signature_bytes);
-
-  let verifying_key = VerifyingKey::from_bytes(&key_array)
-    .map_err(|e| CryptoError::InvalidKey(format!("Invalid public key: {}", e)))?;
-
-  let signature = Signature::from_bytes(&sig_array);
-
-  match verifying_key.verify(data, &signature) {
-    Ok(()) => Ok(true),
-    Err(_) => Ok(false),
-  }
-}
-
 pub fn encrypt_aes_gcm(key: &[u8], nonce: &[u8], data: &[u8]) -> Result<Buffer> {
   if key.len() != 32 {
     return Err(CryptoError::InvalidKey("AES-256 key must be 32 bytes".to_string()).into());
@@ -112,7 +60,12 @@ pub fn encrypt_aes_gcm(key: &[u8], nonce: &[u8], data: &[u8]) -> Result<Buffer>
     return Err(CryptoError::InvalidKey("AES-GCM nonce must be 12 bytes".to_string()).into());
   }
 
-  let cipher = Aes256Gcm::new_from_slice(key)
+  let mut key_array = [0u8; 32];
+  key_array.copy_from_slice(key);
+  let key_material = SecretBytes::new(key_array);
+  key_array.zeroize();
+
+  let cipher = Aes256Gcm::new_from_slice(key_material.as_bytes())
     .map_err(|e| CryptoError::EncryptionFailed(format!("Failed to create cipher: {}", e)))?;
 
   let nonce = Nonce::from_slice(nonce);
@@ -133,7 +86,12 @@ pub fn decrypt_aes_gcm(key: &[u8], nonce: &[u8], encrypted_data: &[u8]) -> Resul
     return Err(CryptoError::InvalidKey("AES-GCM nonce must be 12 bytes".to_string()).into());
   }
 
-  let cipher = Aes256Gcm::new_from_slice(key)
+  let mut key_array = [0u8; 32];
+  key_array.copy_from_slice(key);
+  let key_material = SecretBytes::new(key_array);
+  key_array.zeroize();
+
+  let cipher = Aes256Gcm::new_from_slice(key_material.as_bytes())
     .map_err(|e| CryptoError::DecryptionFailed(format!("Failed to create cipher: {}", e)))?;
 
   let nonce = Nonce::from_slice(nonce);
@@ -150,9 +108,9 @@ pub fn generate_random_bytes(length: usize) -> Result<Buffer> {
 
   let mut bytes = vec![0u8; length];
   let mut rng = OsRng;
-  
+
   rng.fill_bytes(&mut bytes);
-  
+
   Ok(Buffer::from(bytes))
 }
 
@@ -167,33 +125,15 @@ mod tests {
     assert_eq!(hash.len(), 32);
   }
 
-  #[test]
-  fn test_keypair_generation() {
-    let keypair = generate_ed25519_keypair().unwrap();
-    assert_eq!(keypair.public_key.len(), 64); // 32 bytes as hex
-    assert_eq!(keypair.private_key.len(), 64); // 32 bytes as hex
-  }
-
-  #[test]
-  fn test_sign_and_verify() {
-    let keypair = generate_ed25519_keypair().unwrap();
-    let data = b"test message";
-    
-    let signature = sign_with_ed25519(&keypair.private_key, data).unwrap();
-    let is_valid = verify_ed25519_signature(&keypair.public_key, &signature, data).unwrap();
-    
-    assert!(is_valid);
-  }
-
   #[test]
   fn test_aes_encrypt_decrypt() {
     let key = generate_random_bytes(32).unwrap();
     let nonce = generate_random_bytes(12).unwrap();
     let data = b"secret message";
-    
+
     let encrypted = encrypt_aes_gcm(&key, &nonce, data).unwrap();
     let decrypted = decrypt_aes_gcm(&key, &nonce, &encrypted).unwrap();
-    
+
     assert_eq!(data, decrypted.as_ref());
   }
 }
@@ -0,0 +1,99 @@
+use napi::bindgen_prelude::*;
+
+use super::{
+  decrypt_aes_gcm, derive_key, encrypt_aes_gcm, generate_random_bytes, generate_x25519_keypair,
+  x25519_shared_secret, CryptoError,
+};
+use crate::armor;
+use crate::SealedMessage;
+
+const EPHEMERAL_PUBLIC_KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const AES_KEY_LEN: u32 = 32;
+const HKDF_INFO: &[u8] = b"devshare-sealed-box-v1";
+
+/// One-shot hybrid public-key encryption: generates an ephemeral x25519
+/// keypair, does ECDH to `recipient_public_key_hex`, derives an AES-256-GCM
+/// key via HKDF, and encrypts `plaintext`. The sealed message is
+/// `ephemeral_public_key || nonce || ciphertext`, returned both as raw
+/// bytes and as an ASCII-armored text block.
+pub fn seal(recipient_public_key_hex: &str, plaintext: &[u8]) -> Result<SealedMessage> {
+  let ephemeral = generate_x25519_keypair()?;
+  let shared_secret = x25519_shared_secret(&ephemeral.private_key, recipient_public_key_hex)?;
+  let aes_key = derive_key(&shared_secret, &[], HKDF_INFO, AES_KEY_LEN)?;
+
+  let nonce = generate_random_bytes(NONCE_LEN)?;
+  let ciphertext = encrypt_aes_gcm(&aes_key, &nonce, plaintext)?;
+
+  let ephemeral_public_key = hex::decode(&ephemeral.public_key)
+    .map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+  let mut binary = Vec::with_capacity(EPHEMERAL_PUBLIC_KEY_LEN + NONCE_LEN + ciphertext.len());
+  binary.extend_from_slice(&ephemeral_public_key);
+  binary.extend_from_slice(&nonce);
+  binary.extend_from_slice(&ciphertext);
+
+  let armored = armor::armor(&binary);
+
+  Ok(SealedMessage {
+    binary: Buffer::from(binary),
+    armored,
+  })
+}
+
+/// Reverses [`seal`] given the raw `ephemeral_public_key || nonce ||
+/// ciphertext` bytes. Callers holding the armored text form should run it
+/// through [`crate::armor::dearmor`] first.
+pub fn unseal(recipient_private_key_hex: &str, sealed: &[u8]) -> Result<Buffer> {
+  if sealed.len() < EPHEMERAL_PUBLIC_KEY_LEN + NONCE_LEN {
+    return Err(CryptoError::DecryptionFailed("Sealed message is too short".to_string()).into());
+  }
+
+  let (ephemeral_public_key, rest) = sealed.split_at(EPHEMERAL_PUBLIC_KEY_LEN);
+  let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+  let shared_secret =
+    x25519_shared_secret(recipient_private_key_hex, &hex::encode(ephemeral_public_key))?;
+  let aes_key = derive_key(&shared_secret, &[], HKDF_INFO, AES_KEY_LEN)?;
+
+  decrypt_aes_gcm(&aes_key, nonce, ciphertext)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_seal_unseal_round_trip() {
+    let recipient = generate_x25519_keypair().unwrap();
+    let plaintext = b"hello sealed box";
+
+    let sealed = seal(&recipient.public_key, plaintext).unwrap();
+    let decrypted = unseal(&recipient.private_key, &sealed.binary).unwrap();
+
+    assert_eq!(decrypted.as_ref(), plaintext);
+  }
+
+  #[test]
+  fn test_seal_armored_round_trip() {
+    let recipient = generate_x25519_keypair().unwrap();
+    let plaintext = b"through a text-only channel";
+
+    let sealed = seal(&recipient.public_key, plaintext).unwrap();
+    let recovered_binary = armor::dearmor(&sealed.armored).unwrap();
+    let decrypted = unseal(&recipient.private_key, &recovered_binary).unwrap();
+
+    assert_eq!(decrypted.as_ref(), plaintext);
+  }
+
+  #[test]
+  fn test_unseal_with_wrong_key_fails() {
+    let recipient = generate_x25519_keypair().unwrap();
+    let other = generate_x25519_keypair().unwrap();
+    let plaintext = b"not for you";
+
+    let sealed = seal(&recipient.public_key, plaintext).unwrap();
+
+    assert!(unseal(&other.private_key, &sealed.binary).is_err());
+  }
+}
@@ -0,0 +1,187 @@
+use napi::bindgen_prelude::*;
+use once_cell::sync::Lazy;
+use rand::rngs::OsRng;
+use secp256k1::{ecdsa::Signature as EcdsaSignature, All, Message, PublicKey, Secp256k1, SecretKey};
+
+use super::types::Digest;
+use super::CryptoError;
+use crate::KeyPair;
+
+// Building the curve tables costs far more than a single sign/verify call, so
+// we pay for it once per process instead of once per operation.
+static SECP: Lazy<Secp256k1<All>> = Lazy::new(Secp256k1::new);
+
+pub fn generate_secp256k1_keypair() -> Result<KeyPair> {
+  let mut csprng = OsRng;
+  let (secret_key, public_key) = SECP.generate_keypair(&mut csprng);
+
+  Ok(KeyPair {
+    public_key: hex::encode(public_key.serialize()),
+    private_key: hex::encode(secret_key.secret_bytes()),
+  })
+}
+
+pub fn sign_secp256k1(private_key_hex: &str, message_hash: &[u8]) -> Result<String> {
+  let secret_key = parse_secret_key(private_key_hex)?;
+  let message = parse_message(message_hash)?;
+
+  let signature = SECP.sign_ecdsa(&message, &secret_key);
+
+  Ok(hex::encode(signature.serialize_compact()))
+}
+
+pub fn verify_secp256k1(
+  public_key_hex: &str,
+  signature_hex: &str,
+  message_hash: &[u8],
+) -> Result<bool> {
+  let public_key = parse_public_key(public_key_hex)?;
+  let mut signature = parse_signature(signature_hex)?;
+  let message = parse_message(message_hash)?;
+
+  // Externally-produced signatures (other wallets/libraries) aren't always
+  // normalized to low-S; accept either form rather than rejecting valid
+  // signatures outright.
+  signature.normalize_s();
+
+  match SECP.verify_ecdsa(&message, &signature, &public_key) {
+    Ok(()) => Ok(true),
+    Err(_) => Ok(false),
+  }
+}
+
+fn parse_secret_key(hex_str: &str) -> Result<SecretKey> {
+  let bytes =
+    hex::decode(hex_str).map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+  let secret_key = SecretKey::from_slice(&bytes)
+    .map_err(|e| CryptoError::InvalidKey(format!("Invalid secp256k1 private key: {}", e)))?;
+
+  Ok(secret_key)
+}
+
+fn parse_public_key(hex_str: &str) -> Result<PublicKey> {
+  let bytes =
+    hex::decode(hex_str).map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+  if bytes.len() != 33 && bytes.len() != 65 {
+    return Err(
+      CryptoError::InvalidKey(format!(
+        "Public key must be 33 (compressed) or 65 (uncompressed) bytes, got {}",
+        bytes.len()
+      ))
+      .into(),
+    );
+  }
+
+  let public_key = PublicKey::from_slice(&bytes)
+    .map_err(|e| CryptoError::InvalidKey(format!("Invalid secp256k1 public key: {}", e)))?;
+
+  Ok(public_key)
+}
+
+fn parse_signature(signature_hex: &str) -> Result<EcdsaSignature> {
+  let bytes = hex::decode(signature_hex)
+    .map_err(|e| CryptoError::InvalidSignature(format!("Invalid hex: {}", e)))?;
+
+  let signature = if bytes.len() == 64 {
+    EcdsaSignature::from_compact(&bytes)
+      .map_err(|e| CryptoError::InvalidSignature(format!("Invalid compact signature: {}", e)))?
+  } else {
+    EcdsaSignature::from_der(&bytes)
+      .map_err(|e| CryptoError::InvalidSignature(format!("Invalid DER signature: {}", e)))?
+  };
+
+  Ok(signature)
+}
+
+fn parse_message(message_hash: &[u8]) -> Result<Message> {
+  let digest = Digest::from_bytes(
+    message_hash
+      .try_into()
+      .map_err(|_| CryptoError::InvalidKey("Message hash must be a 32-byte digest".to_string()))?,
+  );
+
+  Ok(Message::from_digest(*digest.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_secp256k1_keypair_generation() {
+    let keypair = generate_secp256k1_keypair().unwrap();
+    assert_eq!(keypair.public_key.len(), 66); // 33 bytes compressed, as hex
+    assert_eq!(keypair.private_key.len(), 64); // 32 bytes, as hex
+  }
+
+  #[test]
+  fn test_secp256k1_sign_and_verify() {
+    let keypair = generate_secp256k1_keypair().unwrap();
+    let message_hash = [7u8; 32];
+
+    let signature = sign_secp256k1(&keypair.private_key, &message_hash).unwrap();
+    let is_valid = verify_secp256k1(&keypair.public_key, &signature, &message_hash).unwrap();
+
+    assert!(is_valid);
+  }
+
+  #[test]
+  fn test_secp256k1_verify_uncompressed_public_key() {
+    let secret_key = SecretKey::from_slice(&[3u8; 32]).unwrap();
+    let public_key = PublicKey::from_secret_key(&SECP, &secret_key);
+    let uncompressed_hex = hex::encode(public_key.serialize_uncompressed());
+    let message_hash = [9u8; 32];
+
+    let signature = sign_secp256k1(&hex::encode(secret_key.secret_bytes()), &message_hash).unwrap();
+    let is_valid = verify_secp256k1(&uncompressed_hex, &signature, &message_hash).unwrap();
+
+    assert!(is_valid);
+  }
+
+  #[test]
+  fn test_secp256k1_der_signature_round_trip() {
+    let keypair = generate_secp256k1_keypair().unwrap();
+    let message_hash = [1u8; 32];
+
+    let secret_key = parse_secret_key(&keypair.private_key).unwrap();
+    let message = parse_message(&message_hash).unwrap();
+    let der_signature = SECP.sign_ecdsa(&message, &secret_key).serialize_der();
+
+    let is_valid = verify_secp256k1(
+      &keypair.public_key,
+      &hex::encode(der_signature),
+      &message_hash,
+    )
+    .unwrap();
+
+    assert!(is_valid);
+  }
+
+  #[test]
+  fn test_secp256k1_accepts_high_s_signature() {
+    let keypair = generate_secp256k1_keypair().unwrap();
+    let message_hash = [5u8; 32];
+
+    let secret_key = parse_secret_key(&keypair.private_key).unwrap();
+    let message = parse_message(&message_hash).unwrap();
+
+    // Some libraries/wallets don't normalize to low-S; simulate one by
+    // negating s (mod the curve order) to get the high-S counterpart of the
+    // same (r, s) pair, which must still verify.
+    let mut low_s = SECP.sign_ecdsa(&message, &secret_key);
+    low_s.normalize_s();
+
+    let mut compact = low_s.serialize_compact();
+    let negated_s = SecretKey::from_slice(&compact[32..]).unwrap().negate();
+    compact[32..].copy_from_slice(&negated_s.secret_bytes());
+    let high_s = EcdsaSignature::from_compact(&compact).unwrap();
+
+    let is_valid =
+      verify_secp256k1(&keypair.public_key, &hex::encode(high_s.serialize_compact()), &message_hash)
+        .unwrap();
+
+    assert!(is_valid);
+  }
+}
@@ -0,0 +1,27 @@
+use zeroize::Zeroize;
+
+/// A 32-byte secret value that overwrites its contents when dropped, so key
+/// material does not linger in freed memory after use. The write goes
+/// through the `zeroize` crate, which uses a volatile write the compiler
+/// cannot optimize away.
+pub struct SecretBytes([u8; 32]);
+
+impl SecretBytes {
+  pub fn new(bytes: [u8; 32]) -> Self {
+    SecretBytes(bytes)
+  }
+
+  pub fn as_bytes(&self) -> &[u8; 32] {
+    &self.0
+  }
+
+  pub fn zeroize(&mut self) {
+    self.0.zeroize();
+  }
+}
+
+impl Drop for SecretBytes {
+  fn drop(&mut self) {
+    self.zeroize();
+  }
+}
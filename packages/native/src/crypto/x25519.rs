@@ -0,0 +1,167 @@
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use hkdf::Hkdf;
+use napi::bindgen_prelude::*;
+use rand::rngs::OsRng;
+use sha2::{Digest as Sha2Digest, Sha256, Sha512};
+use x25519_dalek::{PublicKey, StaticSecret};
+use zeroize::Zeroize;
+
+use super::secret_bytes::SecretBytes;
+use super::CryptoError;
+use crate::KeyPair;
+
+pub fn generate_x25519_keypair() -> Result<KeyPair> {
+  let secret = StaticSecret::random_from_rng(OsRng);
+  let public = PublicKey::from(&secret);
+
+  Ok(KeyPair {
+    public_key: hex::encode(public.as_bytes()),
+    private_key: hex::encode(secret.to_bytes()),
+  })
+}
+
+pub fn x25519_shared_secret(my_private_hex: &str, their_public_hex: &str) -> Result<Buffer> {
+  let secret = parse_secret(my_private_hex)?;
+  let public = parse_public(their_public_hex)?;
+
+  let shared = secret.diffie_hellman(&public);
+
+  Ok(Buffer::from(shared.as_bytes().to_vec()))
+}
+
+pub fn derive_key(shared_secret: &[u8], salt: &[u8], info: &[u8], length: u32) -> Result<Buffer> {
+  let hk = Hkdf::<Sha256>::new(Some(salt), shared_secret);
+
+  let mut output = vec![0u8; length as usize];
+  hk.expand(info, &mut output)
+    .map_err(|e| CryptoError::EncryptionFailed(format!("HKDF expand failed: {}", e)))?;
+
+  Ok(Buffer::from(output))
+}
+
+/// Converts an ed25519 identity keypair into an x25519 keypair suitable for
+/// ECDH, via the standard Edwards-to-Montgomery birational map.
+pub fn convert_ed25519_to_x25519(ed25519_keypair: KeyPair) -> Result<KeyPair> {
+  let mut secret_bytes = hex::decode(&ed25519_keypair.private_key)
+    .map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+  let public_bytes = hex::decode(&ed25519_keypair.public_key)
+    .map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+  if secret_bytes.len() != 32 {
+    secret_bytes.zeroize();
+    return Err(CryptoError::InvalidKey("Private key must be 32 bytes".to_string()).into());
+  }
+
+  if public_bytes.len() != 32 {
+    return Err(CryptoError::InvalidKey("Public key must be 32 bytes".to_string()).into());
+  }
+
+  let mut seed = [0u8; 32];
+  seed.copy_from_slice(&secret_bytes);
+  secret_bytes.zeroize();
+  let seed = SecretBytes::new(seed);
+
+  let expanded = Sha512::digest(seed.as_bytes());
+  let mut x25519_secret_bytes = [0u8; 32];
+  x25519_secret_bytes.copy_from_slice(&expanded[..32]);
+  // RFC 7748 section 5 clamping.
+  x25519_secret_bytes[0] &= 248;
+  x25519_secret_bytes[31] &= 127;
+  x25519_secret_bytes[31] |= 64;
+  let x25519_secret = SecretBytes::new(x25519_secret_bytes);
+
+  let mut edwards_bytes = [0u8; 32];
+  edwards_bytes.copy_from_slice(&public_bytes);
+  let x25519_public = CompressedEdwardsY(edwards_bytes)
+    .decompress()
+    .ok_or_else(|| CryptoError::InvalidKey("Invalid ed25519 public key".to_string()))?
+    .to_montgomery();
+
+  Ok(KeyPair {
+    public_key: hex::encode(x25519_public.to_bytes()),
+    private_key: hex::encode(x25519_secret.as_bytes()),
+  })
+}
+
+fn parse_secret(hex_str: &str) -> Result<StaticSecret> {
+  let mut bytes =
+    hex::decode(hex_str).map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+  if bytes.len() != 32 {
+    bytes.zeroize();
+    return Err(CryptoError::InvalidKey("Private key must be 32 bytes".to_string()).into());
+  }
+
+  let mut array = [0u8; 32];
+  array.copy_from_slice(&bytes);
+  bytes.zeroize();
+
+  Ok(StaticSecret::from(array))
+}
+
+fn parse_public(hex_str: &str) -> Result<PublicKey> {
+  let bytes =
+    hex::decode(hex_str).map_err(|e| CryptoError::InvalidKey(format!("Invalid hex: {}", e)))?;
+
+  if bytes.len() != 32 {
+    return Err(CryptoError::InvalidKey("Public key must be 32 bytes".to_string()).into());
+  }
+
+  let mut array = [0u8; 32];
+  array.copy_from_slice(&bytes);
+
+  Ok(PublicKey::from(array))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_x25519_keypair_generation() {
+    let keypair = generate_x25519_keypair().unwrap();
+    assert_eq!(keypair.public_key.len(), 64);
+    assert_eq!(keypair.private_key.len(), 64);
+  }
+
+  #[test]
+  fn test_x25519_shared_secret_agreement() {
+    let alice = generate_x25519_keypair().unwrap();
+    let bob = generate_x25519_keypair().unwrap();
+
+    let alice_shared =
+      x25519_shared_secret(&alice.private_key, &bob.public_key).unwrap();
+    let bob_shared = x25519_shared_secret(&bob.private_key, &alice.public_key).unwrap();
+
+    assert_eq!(alice_shared.as_ref(), bob_shared.as_ref());
+  }
+
+  #[test]
+  fn test_derive_key_length_and_determinism() {
+    let shared_secret = [1u8; 32];
+    let salt = b"salt";
+    let info = b"devshare-aes-key";
+
+    let key_a = derive_key(&shared_secret, salt, info, 32).unwrap();
+    let key_b = derive_key(&shared_secret, salt, info, 32).unwrap();
+
+    assert_eq!(key_a.len(), 32);
+    assert_eq!(key_a.as_ref(), key_b.as_ref());
+  }
+
+  #[test]
+  fn test_convert_ed25519_to_x25519_matches_across_parties() {
+    let alice_ed25519 = crate::crypto::generate_ed25519_keypair().unwrap();
+    let bob_ed25519 = crate::crypto::generate_ed25519_keypair().unwrap();
+
+    let alice_x25519 = convert_ed25519_to_x25519(alice_ed25519).unwrap();
+    let bob_x25519 = convert_ed25519_to_x25519(bob_ed25519).unwrap();
+
+    let alice_shared =
+      x25519_shared_secret(&alice_x25519.private_key, &bob_x25519.public_key).unwrap();
+    let bob_shared =
+      x25519_shared_secret(&bob_x25519.private_key, &alice_x25519.public_key).unwrap();
+
+    assert_eq!(alice_shared.as_ref(), bob_shared.as_ref());
+  }
+}
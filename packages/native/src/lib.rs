@@ -3,9 +3,11 @@
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
+mod armor;
 mod crypto;
 mod compression;
 
+pub use armor::*;
 pub use crypto::*;
 pub use compression::*;
 
@@ -39,6 +41,65 @@ pub fn verify_signature(public_key: String, signature: String, data: Buffer) ->
   crypto::verify_ed25519_signature(&public_key, &signature, &data)
 }
 
+#[napi]
+pub fn generate_secp256k1_keypair() -> Result<KeyPair> {
+  crypto::generate_secp256k1_keypair()
+}
+
+#[napi]
+pub fn sign_secp256k1(private_key_hex: String, message_hash: Buffer) -> Result<String> {
+  crypto::sign_secp256k1(&private_key_hex, &message_hash)
+}
+
+#[napi]
+pub fn verify_secp256k1(
+  public_key_hex: String,
+  signature: String,
+  message_hash: Buffer,
+) -> Result<bool> {
+  crypto::verify_secp256k1(&public_key_hex, &signature, &message_hash)
+}
+
+#[napi]
+pub fn generate_x25519_keypair() -> Result<KeyPair> {
+  crypto::generate_x25519_keypair()
+}
+
+#[napi]
+pub fn x25519_shared_secret(my_private_hex: String, their_public_hex: String) -> Result<Buffer> {
+  crypto::x25519_shared_secret(&my_private_hex, &their_public_hex)
+}
+
+#[napi]
+pub fn derive_key(shared_secret: Buffer, salt: Buffer, info: Buffer, length: u32) -> Result<Buffer> {
+  crypto::derive_key(&shared_secret, &salt, &info, length)
+}
+
+#[napi]
+pub fn convert_ed25519_to_x25519(ed25519_keypair: KeyPair) -> Result<KeyPair> {
+  crypto::convert_ed25519_to_x25519(ed25519_keypair)
+}
+
+#[napi]
+pub fn seal(recipient_public_key_hex: String, plaintext: Buffer) -> Result<SealedMessage> {
+  crypto::seal(&recipient_public_key_hex, &plaintext)
+}
+
+#[napi]
+pub fn unseal(recipient_private_key_hex: String, sealed: Buffer) -> Result<Buffer> {
+  crypto::unseal(&recipient_private_key_hex, &sealed)
+}
+
+#[napi]
+pub fn armor(data: Buffer) -> String {
+  armor::armor(&data)
+}
+
+#[napi]
+pub fn dearmor(armored: String) -> Result<Buffer> {
+  armor::dearmor(&armored).map(Buffer::from)
+}
+
 #[napi]
 pub fn encrypt_aes_gcm(key: Buffer, nonce: Buffer, data: Buffer) -> Result<Buffer> {
   crypto::encrypt_aes_gcm(&key, &nonce, &data)
@@ -54,8 +115,98 @@ pub fn generate_random_bytes(length: u32) -> Result<Buffer> {
   crypto::generate_random_bytes(length as usize)
 }
 
+#[napi]
+pub fn bbs_keygen() -> Result<BbsKeyPair> {
+  crypto::bbs_keygen()
+}
+
+#[napi]
+pub fn bbs_sign(secret_key_hex: String, messages: Vec<Buffer>) -> Result<BbsSignature> {
+  let messages: Vec<Vec<u8>> = messages.into_iter().map(|m| m.to_vec()).collect();
+  crypto::bbs_sign(&secret_key_hex, &messages)
+}
+
+#[napi]
+pub fn bbs_verify(
+  public_key_hex: String,
+  signature: BbsSignature,
+  messages: Vec<Buffer>,
+) -> Result<bool> {
+  let messages: Vec<Vec<u8>> = messages.into_iter().map(|m| m.to_vec()).collect();
+  crypto::bbs_verify(&public_key_hex, &signature, &messages)
+}
+
+#[napi]
+pub fn bbs_create_proof(
+  public_key_hex: String,
+  signature: BbsSignature,
+  messages: Vec<Buffer>,
+  revealed_indices: Vec<u32>,
+  nonce: Buffer,
+) -> Result<BbsProof> {
+  let messages: Vec<Vec<u8>> = messages.into_iter().map(|m| m.to_vec()).collect();
+  crypto::bbs_create_proof(&public_key_hex, &signature, &messages, &revealed_indices, &nonce)
+}
+
+#[napi]
+pub fn bbs_verify_proof(
+  public_key_hex: String,
+  proof: BbsProof,
+  message_count: u32,
+  revealed_indices: Vec<u32>,
+  revealed_messages: Vec<Buffer>,
+  nonce: Buffer,
+) -> Result<bool> {
+  let revealed_messages: Vec<Vec<u8>> =
+    revealed_messages.into_iter().map(|m| m.to_vec()).collect();
+  crypto::bbs_verify_proof(
+    &public_key_hex,
+    &proof,
+    message_count,
+    &revealed_indices,
+    &revealed_messages,
+    &nonce,
+  )
+}
+
 #[napi(object)]
 pub struct KeyPair {
   pub public_key: String,
   pub private_key: String,
 }
+
+#[napi(object)]
+pub struct SealedMessage {
+  pub binary: Buffer,
+  pub armored: String,
+}
+
+#[napi(object)]
+pub struct BbsKeyPair {
+  pub public_key: String,
+  pub secret_key: String,
+}
+
+#[napi(object)]
+pub struct BbsSignature {
+  pub a: String,
+  pub e: String,
+  pub s: String,
+}
+
+#[napi(object)]
+pub struct BbsHiddenResponse {
+  pub index: u32,
+  pub response: String,
+}
+
+#[napi(object)]
+pub struct BbsProof {
+  pub a_prime: String,
+  pub a_bar: String,
+  pub challenge: String,
+  pub z_r: String,
+  pub z_e: String,
+  pub z_s: String,
+  pub hidden_responses: Vec<BbsHiddenResponse>,
+}